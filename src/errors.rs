@@ -0,0 +1,183 @@
+//! User-facing error messages
+//!
+//! Every error string returned to the MCP client is built here, so the wording
+//! stays consistent (and in English) regardless of which tool produced it.
+
+use reqwest::StatusCode;
+
+/// The URL could not be parsed as a GitHub repository
+pub fn invalid_github_url() -> String {
+    "Invalid GitHub URL".to_string()
+}
+
+/// The URL could not be parsed as a GitHub file permalink
+pub fn invalid_permalink() -> String {
+    "Invalid GitHub permalink (expected https://github.com/{owner}/{repo}/blob/{ref}/{path})".to_string()
+}
+
+/// A GitHub API request returned a non-success status
+pub fn api_status(status: StatusCode) -> String {
+    format!("GitHub API error: {}", status)
+}
+
+/// A file could not be read through the contents API
+pub fn file_read(path: &str, status: StatusCode) -> String {
+    format!("Failed to read file '{}': {}", path, status)
+}
+
+/// The code search API returned a non-success status
+pub fn search_failed(status: StatusCode) -> String {
+    format!("Search API error: {} (code search requires authentication and a valid repository)", status)
+}
+
+/// Code search was requested without any GitHub token configured
+pub fn search_requires_auth() -> String {
+    "GitHub code search requires authentication; set GITHUB_TOKEN".to_string()
+}
+
+/// An HTTP request did not complete within the client timeout
+pub fn request_timeout(secs: u64) -> String {
+    format!("Connection timed out after {}s", secs)
+}
+
+/// The host name of an HTTP request could not be resolved
+pub fn unresolved_host(host: &str) -> String {
+    format!("Could not resolve host '{}'", host)
+}
+
+/// A TCP/TLS connection to the host could not be established
+pub fn connection_failed(host: &str) -> String {
+    format!("Could not connect to '{}'", host)
+}
+
+/// An HTTP request failed after the connection was established
+pub fn request_failed(host: &str) -> String {
+    format!("Request to '{}' failed", host)
+}
+
+/// A GitHub App installation token could not be obtained
+pub fn app_auth_failed(reason: &str) -> String {
+    format!("GitHub App authentication failed: {}", reason)
+}
+
+/// A GraphQL-only feature was requested without any GitHub token configured
+pub fn graphql_requires_auth() -> String {
+    "This tool uses the GitHub GraphQL API, which requires authentication; set GITHUB_TOKEN".to_string()
+}
+
+/// The GraphQL API answered with an error payload
+pub fn graphql_error(message: &str) -> String {
+    format!("GitHub GraphQL error: {}", message)
+}
+
+/// A GitHub API response did not have the expected shape
+pub fn invalid_response(what: &str) -> String {
+    format!("Invalid {} response from GitHub API", what)
+}
+
+/// A fetched file could not be parsed in its expected format
+pub fn parse_failed(path: &str, reason: &str) -> String {
+    format!("Failed to parse '{}': {}", path, reason)
+}
+
+/// A tool argument had a value outside the accepted set
+pub fn invalid_option(name: &str, value: &str, expected: &[&str]) -> String {
+    format!("Invalid {} '{}' (expected one of: {})", name, value, expected.join(", "))
+}
+
+/// An `encoding` argument is not a known character encoding label
+pub fn unknown_encoding(label: &str) -> String {
+    format!("Unknown encoding '{}' (use a WHATWG label such as utf-8, latin1 or shift_jis)", label)
+}
+
+/// A pagination cursor was not produced by this server
+pub fn invalid_cursor(cursor: &str) -> String {
+    format!("Invalid cursor '{}' (pass the 'next_cursor' value from a previous response)", cursor)
+}
+
+/// The tool call ran out of its time budget before producing any result
+pub fn deadline_exceeded() -> String {
+    "Tool call deadline exceeded (see GIT_MCP_CALL_DEADLINE_SECS)".to_string()
+}
+
+/// The requested resource does not exist in the repository
+pub fn not_found(what: &str) -> String {
+    format!("{} not found", what)
+}
+
+/// A fork-only operation was requested on a repository that is not a fork
+pub fn not_a_fork(repo: &str) -> String {
+    format!("Repository '{}' is not a fork", repo)
+}
+
+/// A commit SHA argument was not a hexadecimal prefix of usable length
+pub fn invalid_sha(sha: &str) -> String {
+    format!("Invalid commit SHA '{}' (expected 4 to 40 hexadecimal characters)", sha)
+}
+
+/// An abbreviated commit SHA matched no commit or more than one
+pub fn ambiguous_sha(sha: &str) -> String {
+    format!("Commit SHA '{}' is ambiguous or unknown (try a longer prefix)", sha)
+}
+
+/// A resource exceeds the size this server is willing to return
+pub fn too_large(what: &str, size: u64, limit: u64) -> String {
+    format!("'{}' is too large ({} bytes, limit {} bytes)", what, size, limit)
+}
+
+/// A list argument has more entries than a single call accepts
+pub fn too_many_items(name: &str, count: usize, limit: usize) -> String {
+    format!("Too many {} ({}, limit {})", name, count, limit)
+}
+
+/// The requested tool is not provided by this server
+pub fn unknown_tool(name: &str) -> String {
+    format!("Tool '{}' not found", name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repository_and_request_errors() {
+        assert_eq!(invalid_github_url(), "Invalid GitHub URL");
+        assert_eq!(invalid_permalink(), "Invalid GitHub permalink (expected https://github.com/{owner}/{repo}/blob/{ref}/{path})");
+        assert_eq!(api_status(StatusCode::FORBIDDEN), "GitHub API error: 403 Forbidden");
+        assert_eq!(file_read("src/lib.rs", StatusCode::NOT_FOUND), "Failed to read file 'src/lib.rs': 404 Not Found");
+        assert_eq!(search_failed(StatusCode::UNPROCESSABLE_ENTITY), "Search API error: 422 Unprocessable Entity (code search requires authentication and a valid repository)");
+        assert_eq!(search_requires_auth(), "GitHub code search requires authentication; set GITHUB_TOKEN");
+        assert_eq!(request_timeout(30), "Connection timed out after 30s");
+        assert_eq!(unresolved_host("api.github.com"), "Could not resolve host 'api.github.com'");
+        assert_eq!(connection_failed("api.github.com"), "Could not connect to 'api.github.com'");
+        assert_eq!(request_failed("api.github.com"), "Request to 'api.github.com' failed");
+    }
+
+    #[test]
+    fn auth_and_response_errors() {
+        assert_eq!(app_auth_failed("bad key"), "GitHub App authentication failed: bad key");
+        assert_eq!(graphql_requires_auth(), "This tool uses the GitHub GraphQL API, which requires authentication; set GITHUB_TOKEN");
+        assert_eq!(graphql_error("Field 'x' doesn't exist"), "GitHub GraphQL error: Field 'x' doesn't exist");
+        assert_eq!(invalid_response("tree"), "Invalid tree response from GitHub API");
+        assert_eq!(parse_failed("Cargo.toml", "expected `=`"), "Failed to parse 'Cargo.toml': expected `=`");
+    }
+
+    #[test]
+    fn argument_errors() {
+        assert_eq!(invalid_option("sort", "name", &["path", "size"]), "Invalid sort 'name' (expected one of: path, size)");
+        assert_eq!(unknown_encoding("klingon"), "Unknown encoding 'klingon' (use a WHATWG label such as utf-8, latin1 or shift_jis)");
+        assert_eq!(invalid_cursor("abc"), "Invalid cursor 'abc' (pass the 'next_cursor' value from a previous response)");
+        assert_eq!(invalid_sha("xyz"), "Invalid commit SHA 'xyz' (expected 4 to 40 hexadecimal characters)");
+        assert_eq!(ambiguous_sha("abcd"), "Commit SHA 'abcd' is ambiguous or unknown (try a longer prefix)");
+        assert_eq!(too_large("asset.bin", 2048, 1024), "'asset.bin' is too large (2048 bytes, limit 1024 bytes)");
+        assert_eq!(too_many_items("links", 60, 50), "Too many links (60, limit 50)");
+        assert_eq!(unknown_tool("get_everything"), "Tool 'get_everything' not found");
+    }
+
+    #[test]
+    fn outcome_errors() {
+        assert_eq!(deadline_exceeded(), "Tool call deadline exceeded (see GIT_MCP_CALL_DEADLINE_SECS)");
+        assert_eq!(not_found("Directory 'docs'"), "Directory 'docs' not found");
+        assert_eq!(not_a_fork("o/r"), "Repository 'o/r' is not a fork");
+    }
+}
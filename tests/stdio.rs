@@ -0,0 +1,213 @@
+//! End-to-end tests driving the server binary over stdio
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// Runs the server with the given stdin lines, with no credentials and GitHub unreachable
+fn run_server(args: &[&str], input: &str) -> Output {
+    // Route API traffic to a closed local port so no request leaves the machine
+    let closed_port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_git_mcp"))
+        .args(args)
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GIT_MCP_TOKEN_CMD")
+        .env_remove("GIT_MCP_APP_ID")
+        .env_remove("NO_PROXY")
+        .env_remove("no_proxy")
+        .env("HTTPS_PROXY", format!("http://127.0.0.1:{}", closed_port))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn per_call_token_never_reaches_the_log() {
+    let token = "ghp_0123456789abcdefSECRET";
+    let call = format!(
+        r#"{{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{{"name":"get_rate_limit","arguments":{{"token":"{}"}}}}}}"#,
+        token
+    );
+    let output = run_server(&[], &format!("{}\n", call));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stderr.contains("Using per-call token for authentication."), "{}", stderr);
+    assert!(!stderr.contains(token), "{}", stderr);
+    assert!(!stdout.contains(token), "{}", stdout);
+    assert!(stdout.contains(r#""id":1"#), "{}", stdout);
+}
+
+#[test]
+fn tools_call_without_id_still_gets_a_response() {
+    let input = concat!(
+        r#"{"jsonrpc":"2.0","method":"tools/call","params":{"name":"no_such_tool","arguments":{}}}"#, "\n",
+        r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#, "\n",
+    );
+    let output = run_server(&[], input);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    // The notification is not answered, the id-less tools/call is
+    assert_eq!(lines.len(), 1, "{}", stdout);
+    let response: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(response["id"], serde_json::Value::Null);
+    assert_eq!(response["result"]["isError"], serde_json::json!(true));
+    assert_eq!(response["result"]["content"][0]["text"], serde_json::json!("Tool 'no_such_tool' not found"));
+}
+
+#[test]
+fn initialize_echoes_a_supported_protocol_version() {
+    let input = concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"protocolVersion":"2024-11-05","capabilities":{}}}"#, "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"initialize","params":{"protocolVersion":"1999-01-01","capabilities":{}}}"#, "\n",
+    );
+    let output = run_server(&[], input);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let responses: Vec<serde_json::Value> = stdout.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+    assert_eq!(responses.len(), 2, "{}", stdout);
+    assert_eq!(responses[0]["result"]["protocolVersion"], serde_json::json!("2024-11-05"));
+    assert_eq!(responses[0]["result"]["capabilities"], serde_json::json!({ "tools": {} }));
+    // Unknown revisions get the server's latest one
+    assert_eq!(responses[1]["result"]["protocolVersion"], serde_json::json!("2025-06-18"));
+    assert_eq!(responses[1]["result"]["capabilities"]["tools"]["listChanged"], serde_json::json!(false));
+}
+
+#[test]
+fn debug_timing_adds_timing_only_when_requested() {
+    let input = concat!(
+        r#"{"jsonrpc":"2.0","id":1,"method":"tools/call","params":{"name":"get_server_stats","arguments":{}}}"#, "\n",
+        r#"{"jsonrpc":"2.0","id":2,"method":"tools/call","params":{"name":"get_server_stats","arguments":{"_debug_timing":true}}}"#, "\n",
+    );
+    let output = run_server(&[], input);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let results: Vec<serde_json::Value> = stdout.lines().map(|line| {
+        let response: serde_json::Value = serde_json::from_str(line).unwrap();
+        serde_json::from_str(response["result"]["content"][0]["text"].as_str().unwrap()).unwrap()
+    }).collect();
+
+    assert_eq!(results.len(), 2, "{}", stdout);
+    assert!(results[0].get("_timing").is_none(), "{}", results[0]);
+    let timing = &results[1]["_timing"];
+    for field in ["fetch_ms", "parse_ms", "total_ms"] {
+        assert!(timing[field].is_u64(), "{}", timing);
+    }
+    assert_eq!(timing["fetch_ms"].as_u64().unwrap() + timing["parse_ms"].as_u64().unwrap(), timing["total_ms"].as_u64().unwrap());
+}
+
+#[test]
+fn max_items_caps_get_tags_output() {
+    let dir = std::env::temp_dir().join(format!("git-mcp-tags-fixture-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let git = |args: &[&str]| {
+        Command::new("git").arg("-C").arg(&dir).args(["-c", "user.name=t", "-c", "user.email=t@example.com"]).args(args)
+            .output().is_ok_and(|o| o.status.success())
+    };
+    std::fs::create_dir_all(&dir).unwrap();
+    if !git(&["init", "--quiet"]) || !git(&["commit", "--quiet", "--allow-empty", "-m", "init"]) {
+        eprintln!("git not available, skipping");
+        return;
+    }
+    for tag in ["v1.0.0", "v1.1.0", "v2.0.0", "v2.1.0", "v3.0.0"] {
+        assert!(git(&["tag", tag]));
+    }
+
+    let call = serde_json::json!({
+        "jsonrpc": "2.0", "id": 1, "method": "tools/call",
+        "params": { "name": "get_tags", "arguments": { "url": dir.to_str().unwrap(), "max_items": 2 } }
+    });
+    let output = run_server(&[], &format!("{}\n", call));
+    let _ = std::fs::remove_dir_all(&dir);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let response: serde_json::Value = serde_json::from_str(stdout.lines().next().unwrap()).unwrap();
+    let data: serde_json::Value = serde_json::from_str(response["result"]["content"][0]["text"].as_str().unwrap()).unwrap();
+
+    assert_eq!(data["count"], serde_json::json!(5), "{}", data);
+    assert_eq!(data["tags"], serde_json::json!(["v3.0.0", "v2.1.0"]));
+    assert_eq!(data["truncated_by_max_items"], serde_json::json!(["tags"]));
+}
+
+/// Runs the binary with `args` while holding its stdin open, failing if it does not exit on its own
+fn run_without_stdin(args: &[&str]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_git_mcp"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Keep stdin open: a server reading it would block until the deadline below
+    let stdin = child.stdin.take();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while child.try_wait().unwrap().is_none() {
+        if std::time::Instant::now() > deadline {
+            child.kill().unwrap();
+            panic!("{:?} did not exit without input", args);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    drop(stdin);
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn version_and_help_exit_without_reading_stdin() {
+    let version = run_without_stdin(&["--version"]);
+    assert_eq!(version.status.code(), Some(0));
+    assert_eq!(String::from_utf8(version.stdout).unwrap(), format!("git_mcp {}\n", env!("CARGO_PKG_VERSION")));
+
+    let help = run_without_stdin(&["--help"]);
+    assert_eq!(help.status.code(), Some(0));
+    let usage = String::from_utf8(help.stdout).unwrap();
+    assert!(usage.contains("Usage: git_mcp [--help | --version]"), "{}", usage);
+    assert!(usage.contains("GITHUB_TOKEN"), "{}", usage);
+
+    let unknown = run_without_stdin(&["--serve"]);
+    assert_eq!(unknown.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&unknown.stderr).starts_with("Unknown argument '--serve'"));
+}
+
+#[cfg(unix)]
+#[test]
+fn sigterm_exits_cleanly_after_the_in_flight_response() {
+    use std::io::{BufRead, BufReader};
+
+    // A proxy that accepts the connection but stalls, keeping the tool call in flight
+    let proxy = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_git_mcp"))
+        .env_remove("GITHUB_TOKEN")
+        .env_remove("GIT_MCP_TOKEN_CMD")
+        .env_remove("GIT_MCP_APP_ID")
+        .env_remove("NO_PROXY")
+        .env_remove("no_proxy")
+        .env("HTTPS_PROXY", format!("http://{}", proxy.local_addr().unwrap()))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    writeln!(stdin, r#"{{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{{"name":"get_rate_limit","arguments":{{}}}}}}"#).unwrap();
+
+    let (stalled, _) = proxy.accept().unwrap();
+    assert!(Command::new("kill").args(["-TERM", &child.id().to_string()]).status().unwrap().success());
+    std::thread::sleep(std::time::Duration::from_millis(300));
+    // Releasing the connection lets the request fail and its response be written
+    drop(stalled);
+
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let response: serde_json::Value = serde_json::from_str(&lines.next().unwrap().unwrap()).unwrap();
+    assert_eq!(response["id"], serde_json::json!(7));
+    assert_eq!(response["result"]["isError"], serde_json::json!(true));
+    assert!(lines.next().is_none());
+
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[DEBUG] Received SIGTERM, shutting down cleanly."), "{}", stderr);
+    drop(stdin);
+}